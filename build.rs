@@ -0,0 +1,195 @@
+//! Generates the bracket and mirroring lookup tables from the vendored `data/BidiBrackets.txt`
+//! and `data/BidiMirroring.txt`, so that upgrading to a new Unicode release is a matter of
+//! dropping in new data files rather than hand-transcribing `match` arms.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// One entry of `BidiBrackets.txt`: a bracket codepoint, its paired counterpart, and whether it
+/// is the opening (`true`) or closing (`false`) member of the pair.
+struct BracketEntry {
+    codepoint: u32,
+    pair: u32,
+    is_open: bool,
+}
+
+/// One entry of `BidiMirroring.txt`: a codepoint and the glyph it is replaced by in a
+/// right-to-left run.
+struct MirrorEntry {
+    codepoint: u32,
+    mirror: u32,
+}
+
+/// Extract the Unicode version from a UCD data file's first line, which by convention is named
+/// `# <FileName>-X.Y.Z.txt` (e.g. `# BidiBrackets-9.0.0.txt`).
+fn parse_unicode_version(data: &str) -> (u64, u64, u64) {
+    let first_line = data.lines().next().expect("data file is empty");
+    let stem = first_line
+        .trim_start_matches('#')
+        .trim()
+        .trim_end_matches(".txt");
+    let version = stem
+        .rsplit('-')
+        .next()
+        .unwrap_or_else(|| panic!("no version found in header line {:?}", first_line));
+    let mut parts = version.splitn(3, '.');
+    let mut next_part = || {
+        parts
+            .next()
+            .unwrap_or_else(|| panic!("malformed version {:?} in header line {:?}", version, first_line))
+            .parse::<u64>()
+            .unwrap_or_else(|e| panic!("malformed version {:?} in header line {:?}: {}", version, first_line, e))
+    };
+    (next_part(), next_part(), next_part())
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(i) => &line[..i],
+        None => line,
+    }
+}
+
+fn parse_codepoint(field: &str) -> u32 {
+    u32::from_str_radix(field.trim(), 16)
+        .unwrap_or_else(|e| panic!("invalid codepoint {:?}: {}", field, e))
+}
+
+fn parse_bidi_brackets(data: &str) -> Vec<BracketEntry> {
+    let mut entries = Vec::new();
+    for line in data.lines() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        assert_eq!(fields.len(), 3, "malformed BidiBrackets.txt line: {:?}", line);
+        let codepoint = parse_codepoint(fields[0]);
+        let pair = parse_codepoint(fields[1]);
+        let is_open = match fields[2].trim() {
+            "o" => true,
+            "c" => false,
+            other => panic!("unknown Bidi_Paired_Bracket_Type {:?} in line {:?}", other, line),
+        };
+        entries.push(BracketEntry { codepoint, pair, is_open });
+    }
+
+    // The bidi bracket-pairing algorithm depends on open<->close being a true bijection: every
+    // opening bracket's pair must itself be listed as the matching closing bracket, and vice
+    // versa. A one-sided or asymmetric data file would silently break pairing, so fail the build
+    // instead of shipping it.
+    for entry in &entries {
+        let reverse = entries
+            .iter()
+            .find(|e| e.codepoint == entry.pair)
+            .unwrap_or_else(|| panic!("{:04X} pairs with {:04X}, which has no entry", entry.codepoint, entry.pair));
+        assert_eq!(
+            reverse.pair, entry.codepoint,
+            "{:04X} pairs with {:04X}, but {:04X} pairs with {:04X} instead",
+            entry.codepoint, entry.pair, entry.pair, reverse.pair
+        );
+        assert_ne!(
+            reverse.is_open, entry.is_open,
+            "{:04X} and {:04X} are paired but have the same Bidi_Paired_Bracket_Type",
+            entry.codepoint, entry.pair
+        );
+    }
+
+    entries
+}
+
+fn parse_bidi_mirroring(data: &str) -> Vec<MirrorEntry> {
+    let mut entries = Vec::new();
+    for line in data.lines() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        assert_eq!(fields.len(), 2, "malformed BidiMirroring.txt line: {:?}", line);
+        entries.push(MirrorEntry {
+            codepoint: parse_codepoint(fields[0]),
+            mirror: parse_codepoint(fields[1]),
+        });
+    }
+
+    // As with BidiBrackets.txt, mirroring is always its own inverse.
+    for entry in &entries {
+        let reverse = entries
+            .iter()
+            .find(|e| e.codepoint == entry.mirror)
+            .unwrap_or_else(|| panic!("{:04X} mirrors to {:04X}, which has no entry", entry.codepoint, entry.mirror));
+        assert_eq!(
+            reverse.mirror, entry.codepoint,
+            "{:04X} mirrors to {:04X}, but {:04X} mirrors to {:04X} instead",
+            entry.codepoint, entry.mirror, entry.mirror, reverse.mirror
+        );
+    }
+
+    entries
+}
+
+fn emit_bracket_table(out: &mut String, fn_name: &str, entries: &[BracketEntry], want_open: bool) {
+    out.push_str(&format!("pub(crate) fn {}(c: char) -> char {{\n", fn_name));
+    out.push_str("    match c {\n");
+    for entry in entries {
+        if entry.is_open != want_open {
+            continue;
+        }
+        out.push_str(&format!(
+            "        '\\u{{{:04X}}}' => '\\u{{{:04X}}}',\n",
+            entry.codepoint, entry.pair
+        ));
+    }
+    out.push_str("        _ => c,\n    }\n}\n\n");
+}
+
+fn emit_mirror_table(out: &mut String, entries: &[MirrorEntry]) {
+    out.push_str("pub(crate) fn mirror_table(c: char) -> char {\n");
+    out.push_str("    match c {\n");
+    for entry in entries {
+        out.push_str(&format!(
+            "        '\\u{{{:04X}}}' => '\\u{{{:04X}}}',\n",
+            entry.codepoint, entry.mirror
+        ));
+    }
+    out.push_str("        _ => c,\n    }\n}\n\n");
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let brackets_data = fs::read_to_string(Path::new(&manifest_dir).join("data/BidiBrackets.txt"))
+        .expect("failed to read data/BidiBrackets.txt");
+    let mirroring_data = fs::read_to_string(Path::new(&manifest_dir).join("data/BidiMirroring.txt"))
+        .expect("failed to read data/BidiMirroring.txt");
+
+    let brackets = parse_bidi_brackets(&brackets_data);
+    let mirrors = parse_bidi_mirroring(&mirroring_data);
+
+    let brackets_version = parse_unicode_version(&brackets_data);
+    let mirroring_version = parse_unicode_version(&mirroring_data);
+    assert_eq!(
+        brackets_version, mirroring_version,
+        "data/BidiBrackets.txt is version {:?} but data/BidiMirroring.txt is version {:?}",
+        brackets_version, mirroring_version
+    );
+
+    let mut out = String::new();
+    out.push_str("// Generated by build.rs from data/BidiBrackets.txt and data/BidiMirroring.txt. Do not edit.\n\n");
+    out.push_str("/// The version of [Unicode](http://www.unicode.org/) that this version of unicode-brackets is\n");
+    out.push_str("/// based on.\n");
+    out.push_str(&format!(
+        "pub const UNICODE_VERSION: (u64, u64, u64) = ({}, {}, {});\n\n",
+        brackets_version.0, brackets_version.1, brackets_version.2
+    ));
+    emit_bracket_table(&mut out, "to_close_bracket_table", &brackets, true);
+    emit_bracket_table(&mut out, "to_open_bracket_table", &brackets, false);
+    emit_mirror_table(&mut out, &mirrors);
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("tables.rs"), out).expect("failed to write tables.rs");
+
+    println!("cargo:rerun-if-changed=data/BidiBrackets.txt");
+    println!("cargo:rerun-if-changed=data/BidiMirroring.txt");
+}
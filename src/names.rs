@@ -0,0 +1,89 @@
+//! Lookup tables for going between a bracket character, its Unicode character name, and the
+//! LaTeX command that typesets it.
+//!
+//! The names here are the same ones that appear as comments in `data/BidiBrackets.txt`; the
+//! LaTeX commands have no counterpart there.
+
+struct Entry {
+    ch: char,
+    name: &'static str,
+    latex: Option<&'static str>,
+}
+
+static TABLE: &[Entry] = &[
+    Entry { ch: '(', name: "LEFT PARENTHESIS", latex: None },
+    Entry { ch: ')', name: "RIGHT PARENTHESIS", latex: None },
+    Entry { ch: '[', name: "LEFT SQUARE BRACKET", latex: Some("\\lbrack") },
+    Entry { ch: ']', name: "RIGHT SQUARE BRACKET", latex: Some("\\rbrack") },
+    Entry { ch: '{', name: "LEFT CURLY BRACKET", latex: Some("\\lbrace") },
+    Entry { ch: '}', name: "RIGHT CURLY BRACKET", latex: Some("\\rbrace") },
+    Entry { ch: '\u{2308}', name: "LEFT CEILING", latex: Some("\\lceil") },
+    Entry { ch: '\u{2309}', name: "RIGHT CEILING", latex: Some("\\rceil") },
+    Entry { ch: '\u{230A}', name: "LEFT FLOOR", latex: Some("\\lfloor") },
+    Entry { ch: '\u{230B}', name: "RIGHT FLOOR", latex: Some("\\rfloor") },
+    Entry {
+        ch: '\u{27E6}',
+        name: "MATHEMATICAL LEFT WHITE SQUARE BRACKET",
+        latex: Some("\\llbracket"),
+    },
+    Entry {
+        ch: '\u{27E7}',
+        name: "MATHEMATICAL RIGHT WHITE SQUARE BRACKET",
+        latex: Some("\\rrbracket"),
+    },
+    Entry { ch: '\u{27E8}', name: "MATHEMATICAL LEFT ANGLE BRACKET", latex: Some("\\langle") },
+    Entry { ch: '\u{27E9}', name: "MATHEMATICAL RIGHT ANGLE BRACKET", latex: Some("\\rangle") },
+    Entry { ch: '\u{3008}', name: "LEFT ANGLE BRACKET", latex: None },
+    Entry { ch: '\u{3009}', name: "RIGHT ANGLE BRACKET", latex: None },
+    Entry { ch: '\u{300A}', name: "LEFT DOUBLE ANGLE BRACKET", latex: None },
+    Entry { ch: '\u{300B}', name: "RIGHT DOUBLE ANGLE BRACKET", latex: None },
+    Entry { ch: '\u{300C}', name: "LEFT CORNER BRACKET", latex: None },
+    Entry { ch: '\u{300D}', name: "RIGHT CORNER BRACKET", latex: None },
+    Entry { ch: '\u{3010}', name: "LEFT BLACK LENTICULAR BRACKET", latex: None },
+    Entry { ch: '\u{3011}', name: "RIGHT BLACK LENTICULAR BRACKET", latex: None },
+];
+
+/// Look up the Unicode character name of a bracket, e.g. `'\u{2308}'` -> `"LEFT CEILING"`.
+pub fn unicode_name(c: char) -> Option<&'static str> {
+    TABLE.iter().find(|e| e.ch == c).map(|e| e.name)
+}
+
+/// Look up the LaTeX command that typesets a bracket, e.g. `'\u{27E8}'` -> `"\\langle"`.
+pub fn latex_command(c: char) -> Option<&'static str> {
+    TABLE.iter().find(|e| e.ch == c).and_then(|e| e.latex)
+}
+
+/// Look up the bracket with the given Unicode character name, e.g. `"LEFT CEILING"` ->
+/// `'\u{2308}'`.
+pub fn bracket_from_unicode_name(name: &str) -> Option<char> {
+    TABLE.iter().find(|e| e.name == name).map(|e| e.ch)
+}
+
+/// Look up the bracket typeset by the given LaTeX command, e.g. `"\\langle"` -> `'\u{27E8}'`.
+pub fn bracket_from_latex_command(cmd: &str) -> Option<char> {
+    TABLE.iter().find(|e| e.latex == Some(cmd)).map(|e| e.ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_name_round_trips_through_bracket_from_unicode_name() {
+        assert_eq!(unicode_name('\u{2308}'), Some("LEFT CEILING"));
+        assert_eq!(bracket_from_unicode_name("LEFT CEILING"), Some('\u{2308}'));
+        assert_eq!(bracket_from_unicode_name("NOT A BRACKET"), None);
+    }
+
+    #[test]
+    fn latex_command_round_trips_through_bracket_from_latex_command() {
+        assert_eq!(latex_command('\u{27E8}'), Some("\\langle"));
+        assert_eq!(bracket_from_latex_command("\\langle"), Some('\u{27E8}'));
+        assert_eq!(bracket_from_latex_command("\\notacommand"), None);
+    }
+
+    #[test]
+    fn brackets_with_no_latex_command_return_none() {
+        assert_eq!(latex_command('\u{3010}'), None);
+    }
+}
@@ -0,0 +1,170 @@
+//! Directional quotation marks and a straight-to-curly "smart quotes" transform.
+//!
+//! Paired quotation marks behave like brackets for both bidi mirroring and typography, but
+//! [`UnicodeBrackets`](../trait.UnicodeBrackets.html) only covers brackets proper, so this
+//! module adds the analogous API for quotes.
+
+use alloc::string::String;
+
+use UnicodeBrackets;
+
+/// A national convention for which glyphs to use for primary (double) and secondary (single)
+/// quotation marks, for use with [`smarten_quotes`](fn.smarten_quotes.html).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum QuoteStyle {
+    /// `“…”` / `‘…’`, as used in English.
+    English,
+    /// `„…“` / `‚…‘`, as used in German.
+    German,
+    /// `«…»` / `‹…›`, as used in French.
+    French,
+}
+
+impl QuoteStyle {
+    fn open_double(&self) -> char {
+        match *self {
+            QuoteStyle::English => '\u{201C}', // LEFT DOUBLE QUOTATION MARK
+            QuoteStyle::German => '\u{201E}', // DOUBLE LOW-9 QUOTATION MARK
+            QuoteStyle::French => '\u{00AB}', // LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+        }
+    }
+
+    fn close_double(&self) -> char {
+        match *self {
+            QuoteStyle::English => '\u{201D}', // RIGHT DOUBLE QUOTATION MARK
+            QuoteStyle::German => '\u{201C}', // LEFT DOUBLE QUOTATION MARK
+            QuoteStyle::French => '\u{00BB}', // RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+        }
+    }
+
+    fn open_single(&self) -> char {
+        match *self {
+            QuoteStyle::English => '\u{2018}', // LEFT SINGLE QUOTATION MARK
+            QuoteStyle::German => '\u{201A}', // SINGLE LOW-9 QUOTATION MARK
+            QuoteStyle::French => '\u{2039}', // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+        }
+    }
+
+    fn close_single(&self) -> char {
+        match *self {
+            QuoteStyle::English => '\u{2019}', // RIGHT SINGLE QUOTATION MARK
+            QuoteStyle::German => '\u{2018}', // LEFT SINGLE QUOTATION MARK
+            QuoteStyle::French => '\u{203A}', // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+        }
+    }
+}
+
+/// Methods for determining whether a character is an opening or closing quotation mark and for
+/// changing the direction of such characters. Mirrors
+/// [`UnicodeBrackets`](../trait.UnicodeBrackets.html).
+pub trait DirectionalQuotes: Eq {
+    /// Determine whether a character is an opening quotation mark.
+    fn is_open_quote(&self) -> bool {
+        self.to_close_quote() != *self
+    }
+
+    /// Determine whether a character is a closing quotation mark.
+    fn is_close_quote(&self) -> bool {
+        self.to_open_quote() != *self
+    }
+
+    /// Convert a closing quotation mark to an opening quotation mark. Returns `self` if the
+    /// character is not a closing quotation mark.
+    fn to_open_quote(&self) -> Self;
+
+    /// Convert an opening quotation mark to a closing quotation mark. Returns `self` if the
+    /// character is not an opening quotation mark.
+    fn to_close_quote(&self) -> Self;
+}
+
+impl DirectionalQuotes for char {
+    fn to_close_quote(&self) -> char {
+        match *self {
+            '\u{00AB}' => '\u{00BB}', // LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+            '\u{2018}' => '\u{2019}', // LEFT SINGLE QUOTATION MARK
+            '\u{201A}' => '\u{2018}', // SINGLE LOW-9 QUOTATION MARK (German open)
+            '\u{201C}' => '\u{201D}', // LEFT DOUBLE QUOTATION MARK
+            '\u{201E}' => '\u{201C}', // DOUBLE LOW-9 QUOTATION MARK (German open)
+            '\u{2039}' => '\u{203A}', // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+            c => c,
+        }
+    }
+
+    fn to_open_quote(&self) -> char {
+        match *self {
+            '\u{00BB}' => '\u{00AB}', // RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+            '\u{2018}' => '\u{201A}', // LEFT SINGLE QUOTATION MARK (German close)
+            '\u{2019}' => '\u{2018}', // RIGHT SINGLE QUOTATION MARK
+            '\u{201C}' => '\u{201E}', // LEFT DOUBLE QUOTATION MARK (German close)
+            '\u{201D}' => '\u{201C}', // RIGHT DOUBLE QUOTATION MARK
+            '\u{203A}' => '\u{2039}', // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+            c => c,
+        }
+    }
+}
+
+/// Replace straight `"` and `'` quotes in `s` with curly quotes in the given `style`.
+///
+/// The opening glyph is chosen after whitespace, an opening bracket or an opening quote (or at
+/// the start of the string); the closing glyph is chosen everywhere else. Nested quotes alternate between the
+/// primary (double) and secondary (single) marks, matching how `"` and `'` are conventionally
+/// nested in the input.
+pub fn smarten_quotes(s: &str, style: QuoteStyle) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut prev_ended_word = false;
+    for c in s.chars() {
+        match c {
+            '"' => {
+                out.push(if prev_ended_word { style.close_double() } else { style.open_double() });
+            }
+            '\'' => {
+                out.push(if prev_ended_word { style.close_single() } else { style.open_single() });
+            }
+            c => {
+                prev_ended_word = !(c.is_whitespace() || c.is_open_bracket());
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_glyph_smarten_quotes_can_produce_is_open_or_close() {
+        for style in &[QuoteStyle::English, QuoteStyle::German, QuoteStyle::French] {
+            assert!(style.open_double().is_open_quote(), "{:?} open_double", style);
+            assert!(style.close_double().is_close_quote(), "{:?} close_double", style);
+            assert!(style.open_single().is_open_quote(), "{:?} open_single", style);
+            assert!(style.close_single().is_close_quote(), "{:?} close_single", style);
+        }
+    }
+
+    #[test]
+    fn smarten_quotes_uses_english_curly_quotes() {
+        assert_eq!(smarten_quotes("\"hi\"", QuoteStyle::English), "\u{201C}hi\u{201D}");
+    }
+
+    #[test]
+    fn smarten_quotes_alternates_nested_double_and_single_quotes() {
+        assert_eq!(
+            smarten_quotes("\"a 'b' c\"", QuoteStyle::English),
+            "\u{201C}a \u{2018}b\u{2019} c\u{201D}"
+        );
+    }
+
+    #[test]
+    fn smarten_quotes_honors_german_and_french_styles() {
+        assert_eq!(smarten_quotes("\"hi\"", QuoteStyle::German), "\u{201E}hi\u{201C}");
+        assert_eq!(smarten_quotes("\"hi\"", QuoteStyle::French), "\u{00AB}hi\u{00BB}");
+    }
+
+    #[test]
+    fn smarten_quotes_opens_after_whitespace_and_brackets_closes_otherwise() {
+        assert_eq!(smarten_quotes("(\"hi\")", QuoteStyle::English), "(\u{201C}hi\u{201D})");
+        assert_eq!(smarten_quotes("a\"b\"c", QuoteStyle::English), "a\u{201D}b\u{201D}c");
+    }
+}
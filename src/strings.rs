@@ -0,0 +1,100 @@
+//! String-level bracket matching and balancing.
+//!
+//! These functions build on [`UnicodeBrackets`](../trait.UnicodeBrackets.html) to provide the
+//! stack-based scanning that implementing the bidi bracket-pairing algorithm
+//! [(UAX #9)](http://unicode.org/reports/tr9/) or a bracket-aware linter otherwise requires
+//! every caller to hand-roll.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use UnicodeBrackets;
+
+/// Given the byte index of an opening bracket in `s`, find the byte index of its matching
+/// closing bracket.
+///
+/// Returns `None` if `open_byte_idx` is not the start of an opening bracket, or if the bracket
+/// has no match (e.g. the string is truncated or malformed).
+pub fn matching_bracket_index(s: &str, open_byte_idx: usize) -> Option<usize> {
+    let mut chars = s.char_indices().skip_while(|&(i, _)| i < open_byte_idx);
+    let (idx, open_char) = chars.next()?;
+    if idx != open_byte_idx || !open_char.is_open_bracket() {
+        return None;
+    }
+
+    let mut stack = Vec::new();
+    stack.push(open_char);
+    for (i, c) in chars {
+        if c.is_open_bracket() {
+            stack.push(c);
+        } else if c.is_close_bracket() {
+            let open = stack.pop()?;
+            if !open.matches(&c) {
+                return None;
+            }
+            if stack.is_empty() {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Determine whether every opening bracket in `s` is closed by the correct counterpart, in the
+/// correct order, with none left over.
+pub fn is_balanced(s: &str) -> bool {
+    let mut stack = Vec::new();
+    for c in s.chars() {
+        if c.is_open_bracket() {
+            stack.push(c);
+        } else if c.is_close_bracket() {
+            match stack.pop() {
+                Some(open) if open.matches(&c) => (),
+                _ => return false,
+            }
+        }
+    }
+    stack.is_empty()
+}
+
+/// Reverse the character order of `s`, replacing each character with its mirror image.
+///
+/// This is the transformation needed to lay out a right-to-left run that has been reversed into
+/// visual (left-to-right) order, per UAX #9.
+pub fn reverse_brackets(s: &str) -> String {
+    s.chars().rev().map(|c| c.to_mirrored()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_bracket_index_finds_the_counterpart() {
+        assert_eq!(matching_bracket_index("(a[b]c)", 0), Some(6));
+        assert_eq!(matching_bracket_index("(a[b]c)", 2), Some(4));
+    }
+
+    #[test]
+    fn matching_bracket_index_rejects_non_bracket_or_mismatched_input() {
+        assert_eq!(matching_bracket_index("abc", 0), None);
+        assert_eq!(matching_bracket_index("(a]", 0), None);
+    }
+
+    #[test]
+    fn is_balanced_checks_order_and_completeness() {
+        assert!(is_balanced("(a[b]c)"));
+        assert!(!is_balanced("(a[b)c]"));
+        assert!(!is_balanced("(a"));
+        assert!(!is_balanced("a)"));
+    }
+
+    #[test]
+    fn reverse_brackets_swaps_brackets_for_their_mirror() {
+        // Reversing a balanced bracketed string must re-swap each bracket so it still reads as
+        // the same (now mirrored) string, not as literally reversed punctuation.
+        assert_eq!(reverse_brackets("(a)"), "(a)");
+        assert_eq!(reverse_brackets("(a[b]c)"), "(c[b]a)");
+        assert_eq!(reverse_brackets("ab"), "ba");
+    }
+}